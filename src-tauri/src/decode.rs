@@ -0,0 +1,101 @@
+// Reconstructing sent text from raw key-down/key-up timings, the reverse of
+// `morse::text_to_keying_events`.
+
+use crate::morse::token_for_pattern;
+
+/// Default assumed speed until the adaptive estimator has seen enough
+/// elements to produce its own dit-length estimate.
+const DEFAULT_WPM: f64 = 20.0;
+
+/// Tracks a running median of recent dit-length (short) element durations so
+/// the dit/dah and gap boundaries can drift with the user's actual speed
+/// instead of requiring a fixed `wpm`.
+struct AdaptiveDitEstimator {
+    recent_dit_durations_ms: Vec<u64>,
+}
+
+const ADAPTIVE_WINDOW: usize = 20;
+
+impl AdaptiveDitEstimator {
+    fn new() -> Self {
+        Self { recent_dit_durations_ms: Vec::new() }
+    }
+
+    fn dit_ms(&self) -> f64 {
+        if self.recent_dit_durations_ms.is_empty() {
+            return 1200.0 / DEFAULT_WPM;
+        }
+        median(&self.recent_dit_durations_ms)
+    }
+
+    /// Folds in a new element duration, if it looks dit-like relative to the
+    /// current estimate (dahs are ~3x a dit and would drag the window off).
+    fn observe(&mut self, duration_ms: u64, current_dit_ms: f64) {
+        if (duration_ms as f64) < current_dit_ms * 2.0 {
+            self.recent_dit_durations_ms.push(duration_ms);
+            if self.recent_dit_durations_ms.len() > ADAPTIVE_WINDOW {
+                self.recent_dit_durations_ms.remove(0);
+            }
+        }
+    }
+}
+
+fn median(values: &[u64]) -> f64 {
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 0 {
+        (sorted[mid - 1] + sorted[mid]) as f64 / 2.0
+    } else {
+        sorted[mid] as f64
+    }
+}
+
+/// Decodes a sequence of `(key_down_ms, key_up_ms)` timestamps into text.
+/// If `wpm` is `Some`, the dit length is fixed at `1200 / wpm`; if `None`,
+/// the dit length is estimated adaptively from the run itself.
+pub fn decode_keying(events: &[(u64, u64)], wpm: Option<f64>) -> String {
+    let fixed_dit_ms = wpm.map(|w| 1200.0 / w);
+    let mut adaptive = AdaptiveDitEstimator::new();
+    let mut dit_ms = fixed_dit_ms.unwrap_or_else(|| adaptive.dit_ms());
+
+    let mut output = String::new();
+    let mut symbol = String::new();
+    let mut prev_key_up: Option<u64> = None;
+
+    for &(key_down, key_up) in events {
+        if let Some(prev_up) = prev_key_up {
+            let gap_ms = key_down.saturating_sub(prev_up) as f64;
+            if gap_ms > 2.0 * dit_ms {
+                flush_symbol(&mut symbol, &mut output);
+                if gap_ms > 5.0 * dit_ms {
+                    output.push(' ');
+                }
+            }
+        }
+
+        let duration_ms = key_up.saturating_sub(key_down);
+        symbol.push(if (duration_ms as f64) <= 2.0 * dit_ms { '.' } else { '-' });
+
+        if fixed_dit_ms.is_none() {
+            adaptive.observe(duration_ms, dit_ms);
+            dit_ms = adaptive.dit_ms();
+        }
+
+        prev_key_up = Some(key_up);
+    }
+    flush_symbol(&mut symbol, &mut output);
+
+    output
+}
+
+fn flush_symbol(symbol: &mut String, output: &mut String) {
+    if symbol.is_empty() {
+        return;
+    }
+    match token_for_pattern(symbol) {
+        Some(token) => output.push_str(token),
+        None => output.push('?'),
+    }
+    symbol.clear();
+}
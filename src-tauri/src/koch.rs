@@ -0,0 +1,92 @@
+// Koch-method lesson engine: progressive introduction of new characters,
+// practiced only alongside characters already unlocked.
+
+use crate::rng::SeededRng;
+use crate::stats::StatsDocument;
+
+/// Canonical Koch character introduction order used by this app.
+pub const KOCH_ORDER: &[char] = &[
+    'K', 'M', 'U', 'R', 'E', 'S', 'N', 'A', 'P', 'T', 'L', 'W', 'I', '.', 'J', 'Z', '=', 'F', 'O',
+    'Y', 'V', 'G', '5', '9', 'Q', 'H', '3', '8', 'B', '?', '4', '2', '7', 'C', '1', 'D', '6', 'X',
+    '0', ',', '/',
+];
+
+/// A lesson always drills at least the first two characters.
+const MIN_LEVEL: usize = 2;
+
+/// Lifetime accuracy on the newest character above which advancing a level
+/// is recommended.
+const ADVANCE_ACCURACY_THRESHOLD: f64 = 0.9;
+
+/// Minimum attempts on the newest character before its lifetime accuracy is
+/// trusted enough to recommend advancing (avoids a lucky first keypress
+/// doing so).
+const ADVANCE_MIN_ATTEMPTS: u32 = 10;
+
+/// Longest allowed run of the same character in a practice group.
+const MAX_SAME_CHAR_RUN: usize = 2;
+
+/// Returns the characters unlocked at `level` (clamped to `[2, KOCH_ORDER.len()]`).
+pub fn koch_lesson_chars(level: usize) -> Vec<char> {
+    let count = level.clamp(MIN_LEVEL, KOCH_ORDER.len());
+    KOCH_ORDER[..count].to_vec()
+}
+
+/// Generates `group_count` fixed-width groups of `group_size` characters
+/// each, drawn only from the characters unlocked at `level`, using a seeded
+/// RNG so sessions are reproducible. Avoids runs longer than
+/// `MAX_SAME_CHAR_RUN` of the same character.
+pub fn koch_generate(level: usize, group_count: usize, group_size: usize, seed: u64) -> Vec<String> {
+    let chars = koch_lesson_chars(level);
+    if chars.is_empty() || group_size == 0 {
+        return Vec::new();
+    }
+
+    let mut rng = SeededRng::new(seed);
+    let mut groups = Vec::with_capacity(group_count);
+
+    for _ in 0..group_count {
+        let mut group = String::with_capacity(group_size);
+        let mut run_char = ' ';
+        let mut run_len = 0;
+
+        for _ in 0..group_size {
+            let mut next = chars[rng.next_below(chars.len())];
+            if next == run_char && run_len >= MAX_SAME_CHAR_RUN {
+                // Every level unlocks at least two distinct characters, so
+                // there is always another one to break the run with.
+                let others: Vec<char> = chars.iter().copied().filter(|&c| c != run_char).collect();
+                next = others[rng.next_below(others.len())];
+            }
+
+            if next == run_char {
+                run_len += 1;
+            } else {
+                run_char = next;
+                run_len = 1;
+            }
+            group.push(next);
+        }
+
+        groups.push(group);
+    }
+
+    groups
+}
+
+/// Recommends advancing past `level` when the newest unlocked character has
+/// been practiced enough and its *lifetime* accuracy (per `EntryStats`, which
+/// only tracks all-time attempts/correct, not a recent window) exceeds
+/// `ADVANCE_ACCURACY_THRESHOLD`. A user who struggled early and has since
+/// improved may take longer to cross this than a true recent-window
+/// accuracy would allow.
+pub fn should_advance_on_lifetime_accuracy(level: usize, stats: &StatsDocument) -> bool {
+    let chars = koch_lesson_chars(level);
+    let Some(&newest) = chars.last() else {
+        return false;
+    };
+    match stats.chars.get(&newest.to_string()) {
+        Some(entry) if entry.attempts >= ADVANCE_MIN_ATTEMPTS => entry.accuracy() > ADVANCE_ACCURACY_THRESHOLD,
+        _ => false,
+    }
+}
@@ -1,19 +1,130 @@
 use std::fs;
+use tauri::Emitter;
 
+mod decode;
+mod koch;
+mod morse;
+mod rng;
+mod stats;
+mod wordlist;
+
+use rng::SeededRng;
+use wordlist::WeightedWordlistState;
+
+/// Loads the user's wordserver file if given and readable, falling back to
+/// the bundled default list so practice works right after a fresh install.
 #[tauri::command]
-fn load_wordserver_from_path(path: Option<String>) -> Result<Vec<String>, String> {
-    // path is None, use ./wordserver.txt
-    let path = path.unwrap_or("./wordserver.txt".to_string());
-    let txt = fs::read_to_string(&path).map_err(|e| format!("failed to read {}: {}", path, e))?;
-    let mut vec = Vec::new();
-    for line in txt.lines() {
-        let t = line.trim();
-        if !t.is_empty() {
-            vec.push(t.to_string());
+fn load_wordserver_from_path(app: tauri::AppHandle, path: Option<String>) -> Result<Vec<String>, String> {
+    if let Some(path) = path {
+        if let Ok(txt) = fs::read_to_string(&path) {
+            return Ok(wordlist::parse_plain_wordlist(&txt));
         }
     }
+    wordlist::read_builtin(&app, wordlist::DEFAULT_BUILTIN)
+}
+
+/// Loads one of the curated word lists bundled with the app (see
+/// `wordlist::BUILTIN_WORDLISTS`).
+#[tauri::command]
+fn load_builtin_wordlist(app: tauri::AppHandle, name: String) -> Result<Vec<String>, String> {
+    wordlist::read_builtin(&app, &name)
+}
+
+/// Parses `path` as a `word<TAB>weight` file (or a plain one-word-per-line
+/// file, treated as uniform weight) and stores it as the active weighted
+/// word list for subsequent `sample_words` calls. Returns the word count.
+#[tauri::command]
+fn load_weighted_wordlist(path: String, state: tauri::State<WeightedWordlistState>) -> Result<usize, String> {
+    let contents = fs::read_to_string(&path).map_err(|e| format!("failed to read {}: {}", path, e))?;
+    let wordlist = wordlist::parse_weighted_wordlist(&contents);
+    let count = wordlist.len();
+    *state.lock().map_err(|e| e.to_string())? = wordlist;
+    Ok(count)
+}
+
+/// Draws `count` words from the active weighted word list (loaded via
+/// `load_weighted_wordlist`), proportional to their configured weights.
+#[tauri::command]
+fn sample_words(count: usize, seed: u64, state: tauri::State<WeightedWordlistState>) -> Result<Vec<String>, String> {
+    let wordlist = state.lock().map_err(|e| e.to_string())?;
+    let mut rng = SeededRng::new(seed);
+    (0..count)
+        .map(|_| {
+            wordlist
+                .sample(&mut rng)
+                .map(|w| w.to_string())
+                .ok_or_else(|| "no weighted wordlist loaded".to_string())
+        })
+        .collect()
+}
+
+/// Records one practice attempt for a character or word and persists the
+/// updated stats profile.
+#[tauri::command]
+fn record_result(app: tauri::AppHandle, char: String, correct: bool, response_ms: u64) -> Result<stats::StatsDocument, String> {
+    stats::record_result(&app, &char, correct, response_ms)
+}
+
+/// Loads the persisted per-character/per-word practice stats profile.
+#[tauri::command]
+fn load_stats(app: tauri::AppHandle) -> Result<stats::StatsDocument, String> {
+    stats::load_stats(&app)
+}
 
-    Ok(vec)
+/// Resets the practice stats profile back to empty.
+#[tauri::command]
+fn reset_stats(app: tauri::AppHandle) -> Result<stats::StatsDocument, String> {
+    stats::reset_stats(&app)
+}
+
+/// Returns the characters unlocked at a given Koch lesson level.
+#[tauri::command]
+fn koch_lesson_chars(level: usize) -> Vec<char> {
+    koch::koch_lesson_chars(level)
+}
+
+/// Generates randomized fixed-width Koch practice groups for a lesson level.
+#[tauri::command]
+fn koch_generate(level: usize, group_count: usize, group_size: usize, seed: u64) -> Vec<String> {
+    koch::koch_generate(level, group_count, group_size, seed)
+}
+
+/// Whether lifetime accuracy on the newest unlocked character is high enough
+/// to recommend advancing past `level` (not a recent-window accuracy; see
+/// `koch::should_advance_on_lifetime_accuracy`).
+#[tauri::command]
+fn koch_should_advance(app: tauri::AppHandle, level: usize) -> Result<bool, String> {
+    let stats = stats::load_stats(&app)?;
+    Ok(koch::should_advance_on_lifetime_accuracy(level, &stats))
+}
+
+/// Reconstructs sent text from raw `(key_down_ms, key_up_ms)` timestamps,
+/// e.g. from a straight-key or paddle practice mode. If `wpm` is omitted,
+/// the dit/dah and gap boundaries are estimated adaptively as the run
+/// progresses, so decoding still works as the user's speed drifts.
+#[tauri::command]
+fn decode_keying(events: Vec<(u64, u64)>, wpm: Option<f64>) -> String {
+    decode::decode_keying(&events, wpm)
+}
+
+/// Converts `text` into timed keying events, then emits them in order on the
+/// `keyer-event` channel followed by one `keyer-done` event, so the frontend
+/// can drive audio and the visual keyer highlight in sync. Nothing is emitted
+/// if `text` contains an untranslatable character.
+#[tauri::command]
+fn keyer_stream(
+    app: tauri::AppHandle,
+    text: String,
+    wpm: f64,
+    farnsworth_wpm: f64,
+    extra_word_space: f64,
+) -> Result<(), String> {
+    let events = morse::text_to_keying_events(&text, wpm, farnsworth_wpm, extra_word_space)?;
+    for event in events {
+        app.emit("keyer-event", event).map_err(|e| e.to_string())?;
+    }
+    app.emit("keyer-done", ()).map_err(|e| e.to_string())?;
+    Ok(())
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -21,8 +132,20 @@ pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_opener::init())
+        .manage(WeightedWordlistState::default())
         .invoke_handler(tauri::generate_handler![
             load_wordserver_from_path,
+            load_builtin_wordlist,
+            load_weighted_wordlist,
+            sample_words,
+            keyer_stream,
+            decode_keying,
+            record_result,
+            load_stats,
+            reset_stats,
+            koch_lesson_chars,
+            koch_generate,
+            koch_should_advance,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
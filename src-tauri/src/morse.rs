@@ -0,0 +1,216 @@
+// International Morse code table and text -> timed keying event conversion.
+
+/// One element of a keying sequence: the tone/light is either on (a dit or
+/// dah) or off (a gap), held for `duration_ms`.
+#[derive(Clone, Copy, Debug, serde::Serialize)]
+pub struct KeyingEvent {
+    pub on: bool,
+    pub duration_ms: u64,
+}
+
+/// `.`/`-` patterns for the letters, digits, punctuation and common prosigns.
+/// Prosigns are written as the run-together letters sent without inter-letter
+/// spacing (e.g. `<AR>` is "AR" sent as ".-.-.").
+const MORSE_TABLE: &[(&str, &str)] = &[
+    ("A", ".-"), ("B", "-..."), ("C", "-.-."), ("D", "-.."), ("E", "."),
+    ("F", "..-."), ("G", "--."), ("H", "...."), ("I", ".."), ("J", ".---"),
+    ("K", "-.-"), ("L", ".-.."), ("M", "--"), ("N", "-."), ("O", "---"),
+    ("P", ".--."), ("Q", "--.-"), ("R", ".-."), ("S", "..."), ("T", "-"),
+    ("U", "..-"), ("V", "...-"), ("W", ".--"), ("X", "-..-"), ("Y", "-.--"),
+    ("Z", "--.."),
+    ("0", "-----"), ("1", ".----"), ("2", "..---"), ("3", "...--"),
+    ("4", "....-"), ("5", "....."), ("6", "-...."), ("7", "--..."),
+    ("8", "---.."), ("9", "----."),
+    (".", ".-.-.-"), (",", "--..--"), ("?", "..--.."), ("'", ".----."),
+    ("!", "-.-.--"), ("/", "-..-."), ("(", "-.--."), (")", "-.--.-"),
+    ("&", ".-..."), (":", "---..."), (";", "-.-.-."), ("=", "-...-"),
+    ("+", ".-.-."), ("-", "-....-"), ("_", "..--.-"), ("\"", ".-..-."),
+    ("$", "...-..-"), ("@", ".--.-."),
+    // Prosigns are sent as run-together letters, so several share a pattern
+    // with an existing punctuation mark above (<AR>/+, <BT>/=, <KN>/(, <AS>/&).
+    // That's an inherent ambiguity in Morse itself, not a bug in this table:
+    // `token_for_pattern` does a first-match lookup, so on decode these
+    // patterns always resolve to the punctuation reading, never the prosign.
+    ("<AR>", ".-.-."), ("<SK>", "...-.-"), ("<BT>", "-...-"),
+    ("<KN>", "-.--."), ("<AS>", ".-..."), ("<HH>", "........"),
+];
+
+/// Looks up the `.`/`-` pattern for a single uppercase letter/digit/punctuation
+/// token (prosigns are matched as their bracketed form, e.g. `<AR>`).
+fn pattern_for(token: &str) -> Option<&'static str> {
+    MORSE_TABLE
+        .iter()
+        .find(|(t, _)| t.eq_ignore_ascii_case(token))
+        .map(|(_, pattern)| *pattern)
+}
+
+/// Reverse lookup used by the decoder: the letter/digit/punctuation/prosign
+/// token that sends a given `.`/`-` pattern, if any. Where a prosign shares a
+/// pattern with a punctuation mark, the punctuation entry wins (see the
+/// comment on `MORSE_TABLE`) since it appears first.
+pub(crate) fn token_for_pattern(pattern: &str) -> Option<&'static str> {
+    MORSE_TABLE
+        .iter()
+        .find(|(_, p)| *p == pattern)
+        .map(|(token, _)| *token)
+}
+
+/// Splits input text into the tokens `keyer_stream` keys one at a time:
+/// prosigns in `<...>` form, single characters, and spaces (word breaks).
+fn tokenize(text: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '<' {
+            let mut prosign = String::from("<");
+            while let Some(&next) = chars.peek() {
+                prosign.push(next);
+                chars.next();
+                if next == '>' {
+                    break;
+                }
+            }
+            tokens.push(prosign);
+        } else {
+            tokens.push(c.to_string());
+        }
+    }
+    tokens
+}
+
+/// Farnsworth-adjusted spacing unit: dits/dahs and intra-character gaps stay
+/// at the character speed (`wpm`), but inter-character/inter-word gaps use
+/// this stretched unit so the overall sending rate matches `farnsworth_wpm`.
+/// Derived from the standard "PARIS" reference word (50 units per word, of
+/// which 31 are inside characters and 19 are spacing).
+fn farnsworth_unit_ms(dit_ms: f64, wpm: f64, farnsworth_wpm: f64) -> f64 {
+    if farnsworth_wpm >= wpm || farnsworth_wpm <= 0.0 {
+        return dit_ms;
+    }
+    let word_ms = 60_000.0 / farnsworth_wpm;
+    let spacing_unit = (word_ms - 31.0 * dit_ms) / 19.0;
+    spacing_unit.max(dit_ms)
+}
+
+/// Converts `text` into an ordered sequence of on/off keying events using
+/// standard 1:3 dit:dah timing and 1:3:7 element:character:word spacing,
+/// stretching the character/word gaps per Farnsworth if `farnsworth_wpm` is
+/// slower than `wpm`. Returns an error naming the first untranslatable token.
+pub fn text_to_keying_events(
+    text: &str,
+    wpm: f64,
+    farnsworth_wpm: f64,
+    extra_word_space: f64,
+) -> Result<Vec<KeyingEvent>, String> {
+    if !(wpm > 0.0) {
+        return Err(format!("wpm must be positive, got {}", wpm));
+    }
+    if !(farnsworth_wpm > 0.0) {
+        return Err(format!("farnsworth_wpm must be positive, got {}", farnsworth_wpm));
+    }
+
+    let dit_ms = 1200.0 / wpm;
+    let gap_unit_ms = farnsworth_unit_ms(dit_ms, wpm, farnsworth_wpm);
+
+    let mut events = Vec::new();
+    let mut pending_word_gap = false;
+    let mut pending_char_gap = false;
+
+    for token in tokenize(text) {
+        if token == " " {
+            pending_word_gap = true;
+            pending_char_gap = false;
+            continue;
+        }
+
+        let pattern = pattern_for(&token).ok_or_else(|| format!("untranslatable character: {:?}", token))?;
+
+        if pending_word_gap {
+            events.push(gap(((7.0 + extra_word_space) * gap_unit_ms) as u64));
+            pending_word_gap = false;
+        } else if pending_char_gap {
+            events.push(gap((3.0 * gap_unit_ms) as u64));
+        }
+        pending_char_gap = true;
+
+        for (i, symbol) in pattern.chars().enumerate() {
+            if i > 0 {
+                events.push(gap(dit_ms as u64));
+            }
+            let duration = match symbol {
+                '.' => dit_ms,
+                '-' => 3.0 * dit_ms,
+                _ => return Err(format!("invalid morse symbol {:?} in table", symbol)),
+            };
+            events.push(on(duration as u64));
+        }
+    }
+
+    Ok(events)
+}
+
+fn on(duration_ms: u64) -> KeyingEvent {
+    KeyingEvent { on: true, duration_ms }
+}
+
+fn gap(duration_ms: u64) -> KeyingEvent {
+    KeyingEvent { on: false, duration_ms }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn equal_wpm_uses_standard_1_3_7_timing() {
+        // "E" is a single dit; at 20 wpm a dit is 1200/20 = 60ms.
+        let events = text_to_keying_events("E", 20.0, 20.0, 0.0).unwrap();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].on, true);
+        assert_eq!(events[0].duration_ms, 60);
+
+        // "E E" is dit, inter-word gap (7 units), dit.
+        let events = text_to_keying_events("E E", 20.0, 20.0, 0.0).unwrap();
+        let durations: Vec<(bool, u64)> = events.iter().map(|e| (e.on, e.duration_ms)).collect();
+        assert_eq!(durations, vec![(true, 60), (false, 7 * 60), (true, 60)]);
+    }
+
+    #[test]
+    fn farnsworth_stretches_gaps_but_not_elements() {
+        // At 20 wpm / 10 farnsworth wpm, dits/dahs stay at the 20 wpm rate
+        // (60ms dit) while inter-character and inter-word gaps stretch.
+        let dit_ms = 1200.0 / 20.0;
+        let events = text_to_keying_events("EE", 20.0, 10.0, 0.0).unwrap();
+        // dit, inter-character gap, dit - the gap must be wider than the
+        // standard (non-Farnsworth) 3-unit gap at 20 wpm.
+        assert_eq!(events[0].duration_ms, dit_ms as u64);
+        assert!(events[1].duration_ms as f64 > 3.0 * dit_ms);
+        assert_eq!(events[2].duration_ms, dit_ms as u64);
+    }
+
+    #[test]
+    fn farnsworth_faster_than_wpm_is_not_compressed() {
+        // farnsworth_wpm >= wpm should leave standard timing untouched.
+        let standard = text_to_keying_events("EE", 20.0, 20.0, 0.0).unwrap();
+        let faster_farnsworth = text_to_keying_events("EE", 20.0, 30.0, 0.0).unwrap();
+        let standard_durations: Vec<u64> = standard.iter().map(|e| e.duration_ms).collect();
+        let faster_durations: Vec<u64> = faster_farnsworth.iter().map(|e| e.duration_ms).collect();
+        assert_eq!(standard_durations, faster_durations);
+    }
+
+    #[test]
+    fn extra_word_space_widens_inter_word_gap() {
+        let dit_ms = 1200.0 / 20.0;
+        let events = text_to_keying_events("E E", 20.0, 20.0, 2.0).unwrap();
+        // Inter-word gap grows from 7 to (7 + extra_word_space) units.
+        assert_eq!(events[1].duration_ms, (9.0 * dit_ms) as u64);
+    }
+
+    #[test]
+    fn zero_or_negative_wpm_is_an_error() {
+        assert!(text_to_keying_events("E", 0.0, 20.0, 0.0).is_err());
+        assert!(text_to_keying_events("E", -5.0, 20.0, 0.0).is_err());
+        assert!(text_to_keying_events("E", 20.0, 0.0, 0.0).is_err());
+        assert!(text_to_keying_events("E", 20.0, -5.0, 0.0).is_err());
+    }
+}
@@ -0,0 +1,117 @@
+// Per-character/per-word practice statistics, persisted as a JSON profile in
+// the app data directory.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tauri::Manager;
+
+const STATS_VERSION: u32 = 1;
+const STATS_FILENAME: &str = "stats.json";
+
+/// Running accuracy/speed for a single character or word.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct EntryStats {
+    pub attempts: u32,
+    pub correct: u32,
+    /// Rolling average response time, updated incrementally so the whole
+    /// history doesn't need to be kept around.
+    pub avg_response_ms: f64,
+}
+
+impl Default for EntryStats {
+    fn default() -> Self {
+        Self { attempts: 0, correct: 0, avg_response_ms: 0.0 }
+    }
+}
+
+impl EntryStats {
+    fn record(&mut self, correct: bool, response_ms: u64) {
+        self.attempts += 1;
+        if correct {
+            self.correct += 1;
+        }
+        let n = self.attempts as f64;
+        self.avg_response_ms += (response_ms as f64 - self.avg_response_ms) / n;
+    }
+
+    pub fn accuracy(&self) -> f64 {
+        if self.attempts == 0 {
+            0.0
+        } else {
+            self.correct as f64 / self.attempts as f64
+        }
+    }
+}
+
+/// The on-disk stats profile. `version` lets future fields be added to
+/// `EntryStats`/`StatsDocument` without breaking old profiles: unknown
+/// fields are ignored on read and missing ones fall back to `#[serde(default)]`.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct StatsDocument {
+    pub version: u32,
+    #[serde(default)]
+    pub chars: HashMap<String, EntryStats>,
+    #[serde(default)]
+    pub words: HashMap<String, EntryStats>,
+}
+
+impl Default for StatsDocument {
+    fn default() -> Self {
+        Self { version: STATS_VERSION, chars: HashMap::new(), words: HashMap::new() }
+    }
+}
+
+fn stats_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    let dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("could not resolve app data directory: {}", e))?;
+    fs::create_dir_all(&dir).map_err(|e| format!("failed to create {}: {}", dir.display(), e))?;
+    Ok(dir.join(STATS_FILENAME))
+}
+
+fn read_or_default(path: &PathBuf) -> Result<StatsDocument, String> {
+    match fs::read_to_string(path) {
+        Ok(contents) => serde_json::from_str(&contents).map_err(|e| format!("failed to parse {}: {}", path.display(), e)),
+        Err(_) => Ok(StatsDocument::default()),
+    }
+}
+
+fn write(path: &PathBuf, doc: &StatsDocument) -> Result<(), String> {
+    let json = serde_json::to_string_pretty(doc).map_err(|e| e.to_string())?;
+    fs::write(path, json).map_err(|e| format!("failed to write {}: {}", path.display(), e))
+}
+
+/// Records one practice attempt for `key` (a single character or a whole
+/// word) and persists the updated profile. Returns the updated document.
+pub fn record_result(app: &tauri::AppHandle, key: &str, correct: bool, response_ms: u64) -> Result<StatsDocument, String> {
+    let path = stats_path(app)?;
+    let mut doc = read_or_default(&path)?;
+
+    let bucket = if key.chars().count() == 1 { &mut doc.chars } else { &mut doc.words };
+    bucket.entry(key.to_string()).or_default().record(correct, response_ms);
+
+    write(&path, &doc)?;
+    Ok(doc)
+}
+
+/// Loads the stats profile, creating it with defaults on first use.
+pub fn load_stats(app: &tauri::AppHandle) -> Result<StatsDocument, String> {
+    let path = stats_path(app)?;
+    let doc = read_or_default(&path)?;
+    if !path.exists() {
+        write(&path, &doc)?;
+    }
+    Ok(doc)
+}
+
+/// Resets the stats profile back to an empty default document.
+pub fn reset_stats(app: &tauri::AppHandle) -> Result<StatsDocument, String> {
+    let path = stats_path(app)?;
+    let doc = StatsDocument::default();
+    write(&path, &doc)?;
+    Ok(doc)
+}
@@ -0,0 +1,183 @@
+// Loading of bundled and user-supplied word lists.
+
+use std::fs;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use tauri::path::BaseDirectory;
+use tauri::Manager;
+
+use crate::rng::SeededRng;
+
+/// Names of the curated word lists bundled with the app under `resources/`.
+pub const BUILTIN_WORDLISTS: &[&str] = &["common-words", "callsigns", "ham-abbreviations", "pangrams"];
+
+/// The list `load_wordserver_from_path` falls back to when the user hasn't
+/// configured a wordserver file of their own.
+pub const DEFAULT_BUILTIN: &str = "common-words";
+
+/// Splits raw wordlist contents into trimmed, non-empty words, one per line
+/// or whitespace-separated run.
+pub fn parse_plain_wordlist(contents: &str) -> Vec<String> {
+    contents
+        .split_whitespace()
+        .map(|w| w.trim().to_string())
+        .filter(|w| !w.is_empty())
+        .collect()
+}
+
+fn resolve_builtin_path(app: &tauri::AppHandle, name: &str) -> Result<PathBuf, String> {
+    if !BUILTIN_WORDLISTS.contains(&name) {
+        return Err(format!("unknown builtin wordlist: {}", name));
+    }
+    app.path()
+        .resolve(format!("resources/{}.txt", name), BaseDirectory::Resource)
+        .map_err(|e| format!("builtin wordlist resource not found: {}: {}", name, e))
+}
+
+/// Reads one of the bundled word lists by name (see `BUILTIN_WORDLISTS`).
+pub fn read_builtin(app: &tauri::AppHandle, name: &str) -> Result<Vec<String>, String> {
+    let path = resolve_builtin_path(app, name)?;
+    let contents = fs::read_to_string(&path)
+        .map_err(|e| format!("failed to read bundled wordlist {}: {}", path.display(), e))?;
+    Ok(parse_plain_wordlist(&contents))
+}
+
+/// A word list with a frequency weight per word, plus the prefix-sum
+/// ("cumulative weight") array used to sample from it in O(log n).
+#[derive(Default)]
+pub struct WeightedWordlist {
+    words: Vec<String>,
+    cumulative_weights: Vec<f64>,
+}
+
+impl WeightedWordlist {
+    fn total_weight(&self) -> f64 {
+        self.cumulative_weights.last().copied().unwrap_or(0.0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.words.len()
+    }
+
+    /// Draws one word, weighted by its configured frequency, using `rng`.
+    pub fn sample(&self, rng: &mut SeededRng) -> Option<&str> {
+        if self.words.is_empty() {
+            return None;
+        }
+        let target = rng.next_f64() * self.total_weight();
+        let idx = match self
+            .cumulative_weights
+            .binary_search_by(|w| w.partial_cmp(&target).unwrap())
+        {
+            Ok(i) => i,
+            Err(i) => i,
+        };
+        Some(&self.words[idx.min(self.words.len() - 1)])
+    }
+}
+
+/// Per-app shared state holding the most recently loaded weighted word list,
+/// so `sample_words` can draw from it without re-reading the file each time.
+pub type WeightedWordlistState = Mutex<WeightedWordlist>;
+
+/// Parses `word<TAB>weight` lines (weight optional, default 1.0). Files with
+/// no tab on any line are treated as uniform-weight plain word lists, so the
+/// existing one-line-per-word format keeps working unchanged.
+pub fn parse_weighted_wordlist(contents: &str) -> WeightedWordlist {
+    let mut words = Vec::new();
+    let mut weights = Vec::new();
+    for line in contents.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        let mut parts = line.splitn(2, '\t');
+        let word = parts.next().unwrap_or("").trim();
+        if word.is_empty() {
+            continue;
+        }
+        let weight = parts
+            .next()
+            .and_then(|w| w.trim().parse::<f64>().ok())
+            .filter(|w| w.is_finite() && *w >= 0.0)
+            .unwrap_or(1.0);
+        words.push(word.to_string());
+        weights.push(weight);
+    }
+
+    let mut cumulative_weights = Vec::with_capacity(weights.len());
+    let mut running = 0.0;
+    for weight in weights {
+        running += weight;
+        cumulative_weights.push(running);
+    }
+
+    WeightedWordlist { words, cumulative_weights }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_lines_default_to_uniform_weight() {
+        let list = parse_weighted_wordlist("ALPHA\nBRAVO\nCHARLIE\n");
+        assert_eq!(list.words, vec!["ALPHA", "BRAVO", "CHARLIE"]);
+        assert_eq!(list.cumulative_weights, vec![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn tab_separated_weights_are_parsed() {
+        let list = parse_weighted_wordlist("ALPHA\t2\nBRAVO\t1\n");
+        assert_eq!(list.cumulative_weights, vec![2.0, 3.0]);
+    }
+
+    #[test]
+    fn non_finite_or_negative_weight_falls_back_to_one() {
+        let list = parse_weighted_wordlist("ALPHA\tnan\nBRAVO\tinf\nCHARLIE\t-1\n");
+        assert_eq!(list.cumulative_weights, vec![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn sample_on_single_word_list_always_returns_it() {
+        let list = parse_weighted_wordlist("ONLY\t5\n");
+        let mut rng = SeededRng::new(42);
+        for _ in 0..10 {
+            assert_eq!(list.sample(&mut rng), Some("ONLY"));
+        }
+    }
+
+    #[test]
+    fn sample_on_empty_list_returns_none() {
+        let list = parse_weighted_wordlist("");
+        let mut rng = SeededRng::new(1);
+        assert_eq!(list.sample(&mut rng), None);
+    }
+
+    #[test]
+    fn sample_on_all_zero_weights_does_not_panic() {
+        // Ties at 0.0 in the cumulative-weight array must not make
+        // `partial_cmp().unwrap()` panic, and the result must still be a
+        // word from the list rather than an out-of-bounds index.
+        let list = WeightedWordlist {
+            words: vec!["A".to_string(), "B".to_string(), "C".to_string()],
+            cumulative_weights: vec![0.0, 0.0, 0.0],
+        };
+        let mut rng = SeededRng::new(7);
+        for _ in 0..10 {
+            let word = list.sample(&mut rng).unwrap();
+            assert!(list.words.iter().any(|w| w == word));
+        }
+    }
+
+    #[test]
+    fn sample_stays_in_bounds_across_many_seeds() {
+        let list = parse_weighted_wordlist("A\t1\nB\t2\nC\t3\n");
+        for seed in 0..200 {
+            let mut rng = SeededRng::new(seed);
+            let word = list.sample(&mut rng).unwrap();
+            assert!(list.words.iter().any(|w| w == word));
+        }
+    }
+}